@@ -1,5 +1,5 @@
 // MIT License - Copyright (c) 2026 ScreenPrompt Contributors
-// Low-level keyboard hook to capture bare Escape for emergency unlock
+// Low-level keyboard hook to capture a configurable hotkey for emergency unlock
 
 use std::sync::atomic::{AtomicIsize, AtomicU32, Ordering};
 use std::sync::Mutex;
@@ -10,14 +10,29 @@ use windows::Win32::UI::WindowsAndMessaging::{
     CallNextHookEx, GetMessageW, SetWindowsHookExW, UnhookWindowsHookEx,
     PostThreadMessageW, MSG, KBDLLHOOKSTRUCT, WH_KEYBOARD_LL, WM_KEYDOWN, WM_QUIT,
 };
-use windows::Win32::UI::Input::KeyboardAndMouse::VK_ESCAPE;
+use windows::Win32::UI::Input::KeyboardAndMouse::{
+    GetAsyncKeyState, VIRTUAL_KEY, VK_0, VK_A, VK_CONTROL, VK_ESCAPE, VK_F1,
+    VK_LWIN, VK_MENU, VK_OEM_1, VK_OEM_2, VK_OEM_3, VK_OEM_4, VK_OEM_5, VK_OEM_6, VK_OEM_COMMA,
+    VK_OEM_MINUS, VK_OEM_PERIOD, VK_OEM_PLUS, VK_RWIN, VK_SHIFT, VK_SPACE, VK_TAB,
+};
 use windows::Win32::System::Threading::GetCurrentThreadId;
 
+// Modifier bitmask bits for the parsed emergency hotkey.
+const MOD_CTRL: u32 = 1 << 0;
+const MOD_ALT: u32 = 1 << 1;
+const MOD_SHIFT: u32 = 1 << 2;
+const MOD_SUPER: u32 = 1 << 3;
+
 static KB_HOOK_HANDLE: AtomicIsize = AtomicIsize::new(0);
 static KB_HOOK_THREAD_ID: AtomicU32 = AtomicU32::new(0);
 static KB_INSTALL_LOCK: OnceLock<Mutex<()>> = OnceLock::new();
 static APP_HANDLE: OnceLock<Mutex<Option<AppHandle>>> = OnceLock::new();
 
+// Parsed emergency-unlock combo, read directly by the installed hook so it can
+// be re-armed without reinstalling. Defaults to a bare Escape.
+static HOTKEY_VK: AtomicU32 = AtomicU32::new(VK_ESCAPE.0 as u32);
+static HOTKEY_MODS: AtomicU32 = AtomicU32::new(0);
+
 fn get_install_lock() -> &'static Mutex<()> {
     KB_INSTALL_LOCK.get_or_init(|| Mutex::new(()))
 }
@@ -26,6 +41,102 @@ fn get_app_handle_store() -> &'static Mutex<Option<AppHandle>> {
     APP_HANDLE.get_or_init(|| Mutex::new(None))
 }
 
+/// Parse a tao/tauri-style accelerator string into a `(modifiers, vk)` pair.
+///
+/// Tokens are split on `+`; every token but the last is a modifier
+/// (`Ctrl`/`Control`, `Alt`, `Shift`, `Super`/`Win`, and `CmdOrCtrl` which
+/// resolves to Ctrl on Windows), and the last token is the key itself.
+fn parse_accelerator(accel: &str) -> Result<(u32, u32), String> {
+    let tokens: Vec<&str> = accel.split('+').map(|t| t.trim()).collect();
+    if tokens.is_empty() || tokens.iter().any(|t| t.is_empty()) {
+        return Err(format!("Invalid accelerator: {}", accel));
+    }
+
+    let (key_token, mod_tokens) = tokens.split_last().unwrap();
+
+    let mut mods = 0u32;
+    for token in mod_tokens {
+        match token.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" | "cmdorctrl" => mods |= MOD_CTRL,
+            "alt" => mods |= MOD_ALT,
+            "shift" => mods |= MOD_SHIFT,
+            "super" | "win" => mods |= MOD_SUPER,
+            other => return Err(format!("Unknown modifier token: {}", other)),
+        }
+    }
+
+    let vk = parse_key_token(key_token)?;
+    Ok((mods, vk.0 as u32))
+}
+
+/// Map the final accelerator token to a virtual-key code.
+fn parse_key_token(token: &str) -> Result<VIRTUAL_KEY, String> {
+    // Single ASCII letters and digits map straight onto their VK codes.
+    if token.len() == 1 {
+        let c = token.chars().next().unwrap().to_ascii_uppercase();
+        if c.is_ascii_alphabetic() {
+            return Ok(VIRTUAL_KEY(VK_A.0 + (c as u16 - b'A' as u16)));
+        }
+        if c.is_ascii_digit() {
+            return Ok(VIRTUAL_KEY(VK_0.0 + (c as u16 - b'0' as u16)));
+        }
+    }
+
+    // Function keys F1-F24.
+    if let Some(num) = token.strip_prefix(['F', 'f']) {
+        if let Ok(n) = num.parse::<u16>() {
+            if (1..=24).contains(&n) {
+                return Ok(VIRTUAL_KEY(VK_F1.0 + (n - 1)));
+            }
+        }
+    }
+
+    let vk = match token.to_ascii_lowercase().as_str() {
+        "esc" | "escape" => VK_ESCAPE,
+        "space" => VK_SPACE,
+        "tab" => VK_TAB,
+        "," => VK_OEM_COMMA,
+        "-" => VK_OEM_MINUS,
+        "." => VK_OEM_PERIOD,
+        "=" => VK_OEM_PLUS,
+        ";" => VK_OEM_1,
+        "/" => VK_OEM_2,
+        "`" => VK_OEM_3,
+        "[" => VK_OEM_4,
+        "\\" => VK_OEM_5,
+        "]" => VK_OEM_6,
+        _ => return Err(format!("Unknown key token: {}", token)),
+    };
+    Ok(vk)
+}
+
+/// Store a new emergency-unlock combo parsed from an accelerator string. The
+/// running hook picks it up on its next keystroke; no reinstall is required.
+pub fn set_emergency_hotkey(accel: String) -> Result<(), String> {
+    let (mods, vk) = parse_accelerator(&accel)?;
+    HOTKEY_MODS.store(mods, Ordering::Relaxed);
+    HOTKEY_VK.store(vk, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Check whether every modifier in `mods` is currently held down.
+unsafe fn modifiers_held(mods: u32) -> bool {
+    let down = |vk: VIRTUAL_KEY| (GetAsyncKeyState(vk.0 as i32) as u16 & 0x8000) != 0;
+    if mods & MOD_CTRL != 0 && !down(VK_CONTROL) {
+        return false;
+    }
+    if mods & MOD_ALT != 0 && !down(VK_MENU) {
+        return false;
+    }
+    if mods & MOD_SHIFT != 0 && !down(VK_SHIFT) {
+        return false;
+    }
+    if mods & MOD_SUPER != 0 && !(down(VK_LWIN) || down(VK_RWIN)) {
+        return false;
+    }
+    true
+}
+
 unsafe extern "system" fn keyboard_hook_proc(
     n_code: i32,
     w_param: WPARAM,
@@ -33,14 +144,16 @@ unsafe extern "system" fn keyboard_hook_proc(
 ) -> LRESULT {
     if n_code >= 0 && w_param.0 == WM_KEYDOWN as usize {
         let kb_struct = &*(l_param.0 as *const KBDLLHOOKSTRUCT);
-        if kb_struct.vkCode == VK_ESCAPE.0 as u32 {
+        let vk = HOTKEY_VK.load(Ordering::Relaxed);
+        let mods = HOTKEY_MODS.load(Ordering::Relaxed);
+        if kb_struct.vkCode == vk && modifiers_held(mods) {
             // Emit event to frontend
             if let Ok(guard) = get_app_handle_store().lock() {
                 if let Some(ref handle) = *guard {
                     let _ = handle.emit("emergency-unlock", ());
                 }
             }
-            // Don't consume Escape - let it pass through to other apps
+            // Don't consume the key - let it pass through to other apps
         }
     }
 
@@ -0,0 +1,219 @@
+// MIT License - Copyright (c) 2026 ScreenPrompt Contributors
+// Event-driven keyboard-layout change notifications
+
+use std::sync::atomic::{AtomicIsize, AtomicU32, Ordering};
+use std::sync::Mutex;
+use std::sync::OnceLock;
+use tauri::{AppHandle, Emitter};
+use windows::core::w;
+use windows::Win32::Foundation::{HWND, LPARAM, LRESULT, WPARAM};
+use windows::Win32::Globalization::LCIDToLocaleName;
+use windows::Win32::System::LibraryLoader::GetModuleHandleW;
+use windows::Win32::System::Threading::GetCurrentThreadId;
+use windows::Win32::UI::Input::KeyboardAndMouse::GetKeyboardLayout;
+use windows::Win32::UI::WindowsAndMessaging::{
+    CreateWindowExW, DefWindowProcW, DeregisterShellHookWindow, DestroyWindow, GetMessageW,
+    PostThreadMessageW, RegisterClassW, RegisterShellHookWindow, RegisterWindowMessageW,
+    CW_USEDEFAULT, HSHELL_LANGUAGE, MSG, WINDOW_EX_STYLE, WM_QUIT, WNDCLASSW, WS_OVERLAPPED,
+};
+
+static LAYOUT_HWND: AtomicIsize = AtomicIsize::new(0);
+static LAYOUT_THREAD_ID: AtomicU32 = AtomicU32::new(0);
+// The dynamic message id registered for "SHELLHOOK"; shell notifications arrive
+// under this number, so the window proc has to read it at runtime.
+static SHELLHOOK_MSG: AtomicU32 = AtomicU32::new(0);
+static LAYOUT_INSTALL_LOCK: OnceLock<Mutex<()>> = OnceLock::new();
+static APP_HANDLE: OnceLock<Mutex<Option<AppHandle>>> = OnceLock::new();
+
+fn get_install_lock() -> &'static Mutex<()> {
+    LAYOUT_INSTALL_LOCK.get_or_init(|| Mutex::new(()))
+}
+
+fn get_app_handle_store() -> &'static Mutex<Option<AppHandle>> {
+    APP_HANDLE.get_or_init(|| Mutex::new(None))
+}
+
+/// The active keyboard layout, carried to the frontend on every change.
+#[derive(serde::Serialize, Clone)]
+pub struct KeyboardLayout {
+    /// Raw Windows language identifier (the low word of the `HKL`).
+    pub lang_id: u32,
+    /// Resolved BCP-47 locale (e.g. `de-DE`, `fr-FR`, `en-GB`).
+    pub locale: String,
+}
+
+/// Resolve a Windows language identifier to a BCP-47 locale name.
+fn resolve_locale(lang_id: u32) -> String {
+    let mut buf = [0u16; 85]; // LOCALE_NAME_MAX_LENGTH
+    let len = unsafe { LCIDToLocaleName(lang_id, Some(&mut buf), 0) };
+    if len > 0 {
+        String::from_utf16_lossy(&buf[..(len as usize - 1)])
+    } else {
+        String::new()
+    }
+}
+
+/// Read the active input locale and resolve it to a BCP-47 locale name.
+pub fn current_layout() -> KeyboardLayout {
+    let hkl = unsafe { GetKeyboardLayout(0) };
+    let lang_id = (hkl.0 as usize as u32) & 0xFFFF;
+    KeyboardLayout {
+        locale: resolve_locale(lang_id),
+        lang_id,
+    }
+}
+
+/// Build a `KeyboardLayout` from the `HKL` carried by an `HSHELL_LANGUAGE`
+/// notification, whose low word is the new input locale's language id.
+fn layout_from_hkl(hkl: isize) -> KeyboardLayout {
+    let lang_id = (hkl as usize as u32) & 0xFFFF;
+    KeyboardLayout {
+        locale: resolve_locale(lang_id),
+        lang_id,
+    }
+}
+
+unsafe extern "system" fn layout_wnd_proc(
+    hwnd: HWND,
+    msg: u32,
+    w_param: WPARAM,
+    l_param: LPARAM,
+) -> LRESULT {
+    let shellhook = SHELLHOOK_MSG.load(Ordering::Relaxed);
+    if shellhook != 0 && msg == shellhook && w_param.0 == HSHELL_LANGUAGE as usize {
+        if let Ok(guard) = get_app_handle_store().lock() {
+            if let Some(handle) = guard.as_ref() {
+                let _ = handle.emit("keyboard-layout-changed", layout_from_hkl(l_param.0));
+            }
+        }
+    }
+
+    DefWindowProcW(hwnd, msg, w_param, l_param)
+}
+
+/// Spawn a dedicated message-loop thread that emits `keyboard-layout-changed`
+/// whenever the user switches input locale, replacing one-shot detection.
+///
+/// The hidden watcher window opts into shell notifications via
+/// `RegisterShellHookWindow` and listens for `HSHELL_LANGUAGE`. `WM_INPUTLANGCHANGE`
+/// is delivered only to the focus window of the foreground thread, which this
+/// window never is; the shell hook is the in-process way to observe layout
+/// changes across every application.
+pub fn install_layout_watcher(app_handle: AppHandle) -> Result<(), String> {
+    let _guard = get_install_lock()
+        .lock()
+        .map_err(|e| format!("Lock error: {}", e))?;
+
+    if LAYOUT_HWND.load(Ordering::Relaxed) != 0 {
+        return Ok(());
+    }
+
+    {
+        let mut handle_guard = get_app_handle_store()
+            .lock()
+            .map_err(|e| format!("Lock error: {}", e))?;
+        *handle_guard = Some(app_handle);
+    }
+
+    let (tx, rx) = std::sync::mpsc::channel::<Result<(isize, u32), String>>();
+
+    std::thread::spawn(move || unsafe {
+        let hinstance = match GetModuleHandleW(None) {
+            Ok(h) => h,
+            Err(e) => {
+                let _ = tx.send(Err(format!("GetModuleHandleW failed: {}", e)));
+                return;
+            }
+        };
+
+        let class_name = w!("ScreenPromptLayoutWatcher");
+        let wc = WNDCLASSW {
+            lpfnWndProc: Some(layout_wnd_proc),
+            hInstance: hinstance.into(),
+            lpszClassName: class_name,
+            ..Default::default()
+        };
+        RegisterClassW(&wc);
+
+        let hwnd = CreateWindowExW(
+            WINDOW_EX_STYLE(0),
+            class_name,
+            w!("ScreenPrompt Layout Watcher"),
+            WS_OVERLAPPED,
+            CW_USEDEFAULT,
+            CW_USEDEFAULT,
+            0,
+            0,
+            None,
+            None,
+            hinstance.into(),
+            None,
+        );
+
+        let hwnd = match hwnd {
+            Ok(h) => h,
+            Err(e) => {
+                let _ = tx.send(Err(format!("CreateWindowExW failed: {}", e)));
+                return;
+            }
+        };
+
+        // Register the dynamic "SHELLHOOK" message and opt the window into
+        // shell notifications; HSHELL_LANGUAGE is then delivered here on every
+        // input-locale change.
+        let shellhook_msg = RegisterWindowMessageW(w!("SHELLHOOK"));
+        if shellhook_msg == 0 {
+            let _ = DestroyWindow(hwnd);
+            let _ = tx.send(Err("RegisterWindowMessageW(SHELLHOOK) failed".to_string()));
+            return;
+        }
+        SHELLHOOK_MSG.store(shellhook_msg, Ordering::Relaxed);
+
+        if !RegisterShellHookWindow(hwnd).as_bool() {
+            let _ = DestroyWindow(hwnd);
+            let _ = tx.send(Err("RegisterShellHookWindow failed".to_string()));
+            return;
+        }
+
+        let tid = GetCurrentThreadId();
+        let _ = tx.send(Ok((hwnd.0 as isize, tid)));
+
+        let mut msg = MSG::default();
+        while GetMessageW(&mut msg, None, 0, 0).as_bool() {}
+
+        let _ = DeregisterShellHookWindow(hwnd);
+        let _ = DestroyWindow(hwnd);
+    });
+
+    match rx.recv() {
+        Ok(Ok((hwnd_ptr, thread_id))) => {
+            LAYOUT_HWND.store(hwnd_ptr, Ordering::Relaxed);
+            LAYOUT_THREAD_ID.store(thread_id, Ordering::Relaxed);
+            Ok(())
+        }
+        Ok(Err(e)) => Err(e),
+        Err(e) => Err(format!("Layout watcher communication error: {}", e)),
+    }
+}
+
+/// Tear down the layout watcher thread and its hidden window.
+pub fn uninstall_layout_watcher() -> Result<(), String> {
+    let _guard = get_install_lock()
+        .lock()
+        .map_err(|e| format!("Lock error: {}", e))?;
+
+    let thread_id = LAYOUT_THREAD_ID.swap(0, Ordering::Relaxed);
+    LAYOUT_HWND.store(0, Ordering::Relaxed);
+
+    if let Ok(mut guard) = get_app_handle_store().lock() {
+        *guard = None;
+    }
+
+    if thread_id != 0 {
+        unsafe {
+            let _ = PostThreadMessageW(thread_id, WM_QUIT, WPARAM(0), LPARAM(0));
+        }
+    }
+
+    Ok(())
+}
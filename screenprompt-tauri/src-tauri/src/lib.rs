@@ -6,6 +6,10 @@ mod windows_api;
 mod mouse_hook;
 #[cfg(windows)]
 mod keyboard_hook;
+#[cfg(windows)]
+mod theme;
+#[cfg(windows)]
+mod layout;
 
 use tauri::{Manager, WebviewWindow};
 
@@ -33,6 +37,24 @@ fn apply_capture_exclusion(window: WebviewWindow) -> Result<(), String> {
     windows_api::apply_capture_exclusion(window)
 }
 
+#[tauri::command]
+#[cfg(windows)]
+fn set_capture_exclusion(window: WebviewWindow, enabled: bool) -> Result<(), String> {
+    windows_api::set_capture_exclusion(window, enabled)
+}
+
+#[tauri::command]
+#[cfg(windows)]
+fn verify_capture_exclusion(window: WebviewWindow) -> Result<bool, String> {
+    windows_api::verify_capture_exclusion(window)
+}
+
+#[tauri::command]
+#[cfg(windows)]
+fn capture_capability(window: WebviewWindow) -> Result<windows_api::CaptureCapability, String> {
+    windows_api::capture_capability(window)
+}
+
 #[tauri::command]
 #[cfg(windows)]
 fn set_click_through(window: WebviewWindow, enabled: bool) -> Result<(), String> {
@@ -51,8 +73,24 @@ fn get_screen_size(window: WebviewWindow) -> Result<(u32, u32), String> {
 
 #[tauri::command]
 #[cfg(windows)]
-fn install_scroll_hook(window: WebviewWindow) -> Result<(), String> {
-    mouse_hook::install_hook(window)
+fn enumerate_monitors() -> Result<Vec<windows_api::MonitorDescriptor>, String> {
+    windows_api::enumerate_monitors()
+}
+
+#[tauri::command]
+#[cfg(windows)]
+fn move_window_to_monitor(window: WebviewWindow, index: usize) -> Result<(), String> {
+    windows_api::move_window_to_monitor(window, index)
+}
+
+#[tauri::command]
+#[cfg(windows)]
+fn install_scroll_hook(
+    window: WebviewWindow,
+    forward_buttons: Option<bool>,
+    hotspot: Option<(i32, i32, i32, i32)>,
+) -> Result<(), String> {
+    mouse_hook::install_hook(window, forward_buttons.unwrap_or(false), hotspot)
 }
 
 #[tauri::command]
@@ -74,27 +112,53 @@ fn uninstall_keyboard_hook() -> Result<(), String> {
 }
 
 #[tauri::command]
-fn detect_keyboard_layout() -> String {
+#[cfg(windows)]
+fn set_emergency_hotkey(accel: String) -> Result<(), String> {
+    keyboard_hook::set_emergency_hotkey(accel)
+}
+
+#[tauri::command]
+fn get_system_theme() -> String {
     #[cfg(windows)]
     {
-        // GetKeyboardLayout returns the active input locale for the current thread.
-        // The low word is the language identifier.
-        // Hungarian: 0x040E, English-US: 0x0409, English-UK: 0x0809
-        use windows::Win32::UI::Input::KeyboardAndMouse::GetKeyboardLayout;
-        let hkl = unsafe { GetKeyboardLayout(0) };
-        let lang_id = (hkl.0 as u32) & 0xFFFF;
-        if lang_id == 0x040E {
-            "hu".to_string()
-        } else {
-            "en".to_string()
-        }
+        theme::get_system_theme()
     }
     #[cfg(not(windows))]
     {
-        "en".to_string()
+        "light".to_string()
     }
 }
 
+#[tauri::command]
+#[cfg(windows)]
+fn install_theme_watcher(app_handle: tauri::AppHandle) -> Result<(), String> {
+    theme::install_theme_watcher(app_handle)
+}
+
+#[tauri::command]
+#[cfg(windows)]
+fn uninstall_theme_watcher() -> Result<(), String> {
+    theme::uninstall_theme_watcher()
+}
+
+#[tauri::command]
+#[cfg(windows)]
+fn install_layout_watcher(app_handle: tauri::AppHandle) -> Result<(), String> {
+    layout::install_layout_watcher(app_handle)
+}
+
+#[tauri::command]
+#[cfg(windows)]
+fn uninstall_layout_watcher() -> Result<(), String> {
+    layout::uninstall_layout_watcher()
+}
+
+#[tauri::command]
+#[cfg(windows)]
+fn get_keyboard_layout() -> layout::KeyboardLayout {
+    layout::current_layout()
+}
+
 #[tauri::command]
 fn launch_update_installer(path: String) -> Result<(), String> {
     std::process::Command::new(&path)
@@ -117,6 +181,16 @@ fn check_windows_version() -> Result<String, String> {
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    // Opt into per-monitor-DPI-v2 before Tauri creates the main window;
+    // SetProcessDpiAwarenessContext fails once a DPI context is bound, so this
+    // has to happen ahead of window creation rather than in `.setup()`.
+    #[cfg(windows)]
+    {
+        if let Err(e) = windows_api::set_dpi_awareness() {
+            log::warn!("Failed to set DPI awareness: {}", e);
+        }
+    }
+
     tauri::Builder::default()
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_shell::init())
@@ -154,14 +228,25 @@ pub fn run() {
             show_ethical_notice,
             check_windows_version,
             apply_capture_exclusion,
+            set_capture_exclusion,
+            verify_capture_exclusion,
+            capture_capability,
             set_click_through,
             get_screen_size,
+            enumerate_monitors,
+            move_window_to_monitor,
             install_scroll_hook,
             uninstall_scroll_hook,
             install_keyboard_hook,
             uninstall_keyboard_hook,
+            set_emergency_hotkey,
+            get_system_theme,
+            install_theme_watcher,
+            uninstall_theme_watcher,
             launch_update_installer,
-            detect_keyboard_layout,
+            install_layout_watcher,
+            uninstall_layout_watcher,
+            get_keyboard_layout,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -1,17 +1,25 @@
 // MIT License - Copyright (c) 2026 ScreenPrompt Contributors
 // Low-level mouse hook for scroll-through in locked (click-through) mode
 
-use std::sync::atomic::{AtomicIsize, AtomicU32, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicIsize, AtomicU32, Ordering};
 use std::sync::Mutex;
 use std::sync::OnceLock;
 use tauri::WebviewWindow;
 use windows::Win32::Foundation::{HWND, LPARAM, LRESULT, POINT, RECT, WPARAM};
 use windows::Win32::UI::WindowsAndMessaging::{
     CallNextHookEx, GetMessageW, PostMessageW, SetWindowsHookExW, UnhookWindowsHookEx,
-    GetWindowRect, MSG, MSLLHOOKSTRUCT, WH_MOUSE_LL, WM_MOUSEWHEEL,
-    PostThreadMessageW, WM_QUIT,
+    GetWindowRect, MK_CONTROL, MK_LBUTTON, MK_MBUTTON, MK_RBUTTON, MK_SHIFT, MSG, MSLLHOOKSTRUCT,
+    WH_MOUSE_LL, WM_LBUTTONDOWN, WM_LBUTTONUP, WM_MOUSEMOVE, WM_MOUSEWHEEL, WM_RBUTTONDOWN,
+    WM_RBUTTONUP, PostThreadMessageW, WM_QUIT,
+};
+use windows::Win32::Graphics::Gdi::{
+    MonitorFromPoint, MonitorFromWindow, ScreenToClient, MONITOR_DEFAULTTONEAREST,
 };
 use windows::Win32::System::Threading::GetCurrentThreadId;
+use windows::Win32::UI::Input::KeyboardAndMouse::{
+    GetKeyState, VIRTUAL_KEY, VK_CONTROL, VK_LBUTTON, VK_MBUTTON, VK_RBUTTON, VK_SHIFT,
+};
+use windows::Win32::UI::HiDpi::{GetDpiForMonitor, MDT_EFFECTIVE_DPI};
 
 // Store raw pointer values as atomics to avoid Send issues
 static HOOK_HANDLE: AtomicIsize = AtomicIsize::new(0);
@@ -19,16 +27,39 @@ static WINDOW_HANDLE: AtomicIsize = AtomicIsize::new(0);
 static HOOK_THREAD_ID: AtomicU32 = AtomicU32::new(0);
 static INSTALL_LOCK: OnceLock<Mutex<()>> = OnceLock::new();
 
+// When set, left/right button and move messages are forwarded too, so users can
+// click links, drag the scrollbar and trigger hover states even while the
+// window is visually click-through.
+static FORWARD_BUTTONS: AtomicBool = AtomicBool::new(false);
+// Optional interactive hotspot (client-relative, physical pixels). Button/move
+// forwarding only fires while the cursor is inside it; scroll is unaffected.
+static HOTSPOT: OnceLock<Mutex<Option<RECT>>> = OnceLock::new();
+
 fn get_install_lock() -> &'static Mutex<()> {
     INSTALL_LOCK.get_or_init(|| Mutex::new(()))
 }
 
+fn get_hotspot() -> &'static Mutex<Option<RECT>> {
+    HOTSPOT.get_or_init(|| Mutex::new(None))
+}
+
 unsafe extern "system" fn mouse_hook_proc(
     n_code: i32,
     w_param: WPARAM,
     l_param: LPARAM,
 ) -> LRESULT {
-    if n_code >= 0 && w_param.0 == WM_MOUSEWHEEL as usize {
+    if n_code < 0 {
+        return CallNextHookEx(None, n_code, w_param, l_param);
+    }
+
+    let msg = w_param.0 as u32;
+    let is_wheel = msg == WM_MOUSEWHEEL;
+    let is_button = matches!(
+        msg,
+        WM_LBUTTONDOWN | WM_LBUTTONUP | WM_RBUTTONDOWN | WM_RBUTTONUP | WM_MOUSEMOVE
+    );
+
+    if is_wheel || (is_button && FORWARD_BUTTONS.load(Ordering::Relaxed)) {
         let hwnd_val = WINDOW_HANDLE.load(Ordering::Relaxed);
         if hwnd_val != 0 {
             let hwnd = HWND(hwnd_val as *mut core::ffi::c_void);
@@ -37,14 +68,32 @@ unsafe extern "system" fn mouse_hook_proc(
 
             let mut rect = RECT::default();
             if GetWindowRect(hwnd, &mut rect).is_ok() && cursor_in_rect(&cursor, &rect) {
-                // Forward the scroll event to the webview
-                let hi_word = (mouse_struct.mouseData >> 16) as i16;
-                let w = WPARAM((hi_word as u16 as usize) << 16);
-                let l = LPARAM(
-                    ((cursor.y & 0xFFFF) << 16 | (cursor.x & 0xFFFF)) as isize,
-                );
-                let _ = PostMessageW(hwnd, WM_MOUSEWHEEL, w, l);
-                return LRESULT(1); // Consume the event
+                if is_wheel {
+                    // The wheel delta rides in the high word of mouseData; the
+                    // LPARAM carries the cursor position in screen coordinates
+                    // that the target interprets per its own DPI context. On
+                    // mixed-DPI setups translate the physical point into the
+                    // window's DPI space first so the scroll lands correctly.
+                    let point = translate_to_window_dpi(cursor, hwnd, &rect);
+                    let hi_word = (mouse_struct.mouseData >> 16) as i16;
+                    let w = WPARAM((hi_word as u16 as usize) << 16);
+                    let l = LPARAM(((point.y & 0xFFFF) << 16 | (point.x & 0xFFFF)) as isize);
+                    let _ = PostMessageW(hwnd, WM_MOUSEWHEEL, w, l);
+                    return LRESULT(1); // Consume the event
+                }
+
+                // Button/move messages expect client coordinates in the LPARAM.
+                // GetWindowRect yields the window rect, whose origin differs from
+                // the client origin by any non-client frame; go through
+                // ScreenToClient so the forwarded point is correct regardless of
+                // frame, and carry the live button/modifier state as MK_* flags.
+                let mut client = cursor;
+                let _ = ScreenToClient(hwnd, &mut client);
+                if cursor_in_hotspot(client.x, client.y) {
+                    let l = LPARAM(((client.y & 0xFFFF) << 16 | (client.x & 0xFFFF)) as isize);
+                    let _ = PostMessageW(hwnd, msg, mouse_key_state(), l);
+                    return LRESULT(1); // Consume the event
+                }
             }
         }
     }
@@ -52,11 +101,96 @@ unsafe extern "system" fn mouse_hook_proc(
     CallNextHookEx(None, n_code, w_param, l_param)
 }
 
+// Effective DPI of the monitor that contains `point`, defaulting to 96.
+unsafe fn monitor_dpi_for_point(point: POINT) -> u32 {
+    let hmon = MonitorFromPoint(point, MONITOR_DEFAULTTONEAREST);
+    let mut dpi_x = 96u32;
+    let mut dpi_y = 96u32;
+    let _ = GetDpiForMonitor(hmon, MDT_EFFECTIVE_DPI, &mut dpi_x, &mut dpi_y);
+    dpi_x
+}
+
+// Effective DPI of the monitor the target window currently lives on.
+unsafe fn monitor_dpi_for_window(hwnd: HWND) -> u32 {
+    let hmon = MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST);
+    let mut dpi_x = 96u32;
+    let mut dpi_y = 96u32;
+    let _ = GetDpiForMonitor(hmon, MDT_EFFECTIVE_DPI, &mut dpi_x, &mut dpi_y);
+    dpi_x
+}
+
+// Translate a physical screen cursor into the coordinate space the target
+// window expects. When the cursor's monitor and the window's monitor share a
+// DPI this is the identity; when they differ the cursor's offset from the
+// window rect's origin is rescaled by the DPI ratio so the forwarded point
+// maps to the same logical spot.
+unsafe fn translate_to_window_dpi(cursor: POINT, hwnd: HWND, rect: &RECT) -> POINT {
+    let cursor_dpi = monitor_dpi_for_point(cursor);
+    let window_dpi = monitor_dpi_for_window(hwnd);
+    if cursor_dpi == window_dpi || cursor_dpi == 0 {
+        return cursor;
+    }
+    let scale = window_dpi as f64 / cursor_dpi as f64;
+    POINT {
+        x: rect.left + (((cursor.x - rect.left) as f64) * scale).round() as i32,
+        y: rect.top + (((cursor.y - rect.top) as f64) * scale).round() as i32,
+    }
+}
+
+// Current mouse-button and modifier state packed as MK_* flags, as the WPARAM
+// of a forwarded button/move message would normally carry.
+unsafe fn mouse_key_state() -> WPARAM {
+    let down = |vk: VIRTUAL_KEY| (GetKeyState(vk.0 as i32) as u16 & 0x8000) != 0;
+    let mut flags = 0u32;
+    if down(VK_LBUTTON) {
+        flags |= MK_LBUTTON.0;
+    }
+    if down(VK_RBUTTON) {
+        flags |= MK_RBUTTON.0;
+    }
+    if down(VK_MBUTTON) {
+        flags |= MK_MBUTTON.0;
+    }
+    if down(VK_CONTROL) {
+        flags |= MK_CONTROL.0;
+    }
+    if down(VK_SHIFT) {
+        flags |= MK_SHIFT.0;
+    }
+    WPARAM(flags as usize)
+}
+
 fn cursor_in_rect(pt: &POINT, rect: &RECT) -> bool {
     pt.x >= rect.left && pt.x <= rect.right && pt.y >= rect.top && pt.y <= rect.bottom
 }
 
-pub fn install_hook(window: WebviewWindow) -> Result<(), String> {
+// A cursor is "in the hotspot" when no hotspot is set (the whole window is
+// interactive) or when it falls inside the configured client-relative rect.
+fn cursor_in_hotspot(x: i32, y: i32) -> bool {
+    match get_hotspot().lock() {
+        Ok(guard) => match *guard {
+            Some(r) => x >= r.left && x <= r.right && y >= r.top && y <= r.bottom,
+            None => true,
+        },
+        Err(_) => true,
+    }
+}
+
+pub fn install_hook(
+    window: WebviewWindow,
+    forward_buttons: bool,
+    hotspot: Option<(i32, i32, i32, i32)>,
+) -> Result<(), String> {
+    FORWARD_BUTTONS.store(forward_buttons, Ordering::Relaxed);
+    if let Ok(mut guard) = get_hotspot().lock() {
+        *guard = hotspot.map(|(left, top, right, bottom)| RECT {
+            left,
+            top,
+            right,
+            bottom,
+        });
+    }
+
     let _guard = get_install_lock()
         .lock()
         .map_err(|e| format!("Lock error: {}", e))?;
@@ -0,0 +1,186 @@
+// MIT License - Copyright (c) 2026 ScreenPrompt Contributors
+// System theme detection plus live dark/light change notifications
+
+use std::sync::atomic::{AtomicIsize, AtomicU32, Ordering};
+use std::sync::Mutex;
+use std::sync::OnceLock;
+use tauri::{AppHandle, Emitter};
+use windows::core::{w, PCWSTR};
+use windows::Win32::Foundation::{HWND, LPARAM, LRESULT, WPARAM};
+use windows::Win32::System::LibraryLoader::GetModuleHandleW;
+use windows::Win32::System::Registry::{
+    RegGetValueW, HKEY_CURRENT_USER, RRF_RT_REG_DWORD,
+};
+use windows::Win32::System::Threading::GetCurrentThreadId;
+use windows::Win32::UI::WindowsAndMessaging::{
+    CreateWindowExW, DefWindowProcW, DestroyWindow, GetMessageW, PostThreadMessageW,
+    RegisterClassW, CW_USEDEFAULT, MSG, WINDOW_EX_STYLE, WM_SETTINGCHANGE, WM_QUIT, WNDCLASSW,
+    WS_OVERLAPPED,
+};
+
+static THEME_HWND: AtomicIsize = AtomicIsize::new(0);
+static THEME_THREAD_ID: AtomicU32 = AtomicU32::new(0);
+static THEME_INSTALL_LOCK: OnceLock<Mutex<()>> = OnceLock::new();
+static APP_HANDLE: OnceLock<Mutex<Option<AppHandle>>> = OnceLock::new();
+
+fn get_install_lock() -> &'static Mutex<()> {
+    THEME_INSTALL_LOCK.get_or_init(|| Mutex::new(()))
+}
+
+fn get_app_handle_store() -> &'static Mutex<Option<AppHandle>> {
+    APP_HANDLE.get_or_init(|| Mutex::new(None))
+}
+
+/// Read the current OS apps theme: `"light"` or `"dark"`.
+///
+/// Mirrors winit's dark_mode detection by reading the `AppsUseLightTheme`
+/// value under the Personalize key (absent or non-zero means light).
+pub fn get_system_theme() -> String {
+    let mut data = 0u32;
+    let mut size = std::mem::size_of::<u32>() as u32;
+    let status = unsafe {
+        RegGetValueW(
+            HKEY_CURRENT_USER,
+            w!("Software\\Microsoft\\Windows\\CurrentVersion\\Themes\\Personalize"),
+            w!("AppsUseLightTheme"),
+            RRF_RT_REG_DWORD,
+            None,
+            Some(&mut data as *mut u32 as *mut core::ffi::c_void),
+            Some(&mut size),
+        )
+    };
+
+    // A missing value defaults to light, matching the OS default.
+    if status.is_ok() && data == 0 {
+        "dark".to_string()
+    } else {
+        "light".to_string()
+    }
+}
+
+unsafe extern "system" fn theme_wnd_proc(
+    hwnd: HWND,
+    msg: u32,
+    w_param: WPARAM,
+    l_param: LPARAM,
+) -> LRESULT {
+    if msg == WM_SETTINGCHANGE && l_param.0 != 0 {
+        // The changed area arrives as a wide string in LPARAM; an immersive
+        // color-set change is how the OS signals a light/dark toggle.
+        let area = PCWSTR(l_param.0 as *const u16);
+        if !area.is_null() && area.to_string().as_deref() == Ok("ImmersiveColorSet") {
+            if let Ok(guard) = get_app_handle_store().lock() {
+                if let Some(handle) = guard.as_ref() {
+                    let _ = handle.emit("system-theme-changed", get_system_theme());
+                }
+            }
+        }
+    }
+
+    DefWindowProcW(hwnd, msg, w_param, l_param)
+}
+
+/// Spawn a dedicated message-loop thread that emits `system-theme-changed`
+/// whenever the user toggles the OS theme at runtime.
+pub fn install_theme_watcher(app_handle: AppHandle) -> Result<(), String> {
+    let _guard = get_install_lock()
+        .lock()
+        .map_err(|e| format!("Lock error: {}", e))?;
+
+    if THEME_HWND.load(Ordering::Relaxed) != 0 {
+        return Ok(());
+    }
+
+    {
+        let mut handle_guard = get_app_handle_store()
+            .lock()
+            .map_err(|e| format!("Lock error: {}", e))?;
+        *handle_guard = Some(app_handle);
+    }
+
+    let (tx, rx) = std::sync::mpsc::channel::<Result<(isize, u32), String>>();
+
+    std::thread::spawn(move || unsafe {
+        let hinstance = match GetModuleHandleW(None) {
+            Ok(h) => h,
+            Err(e) => {
+                let _ = tx.send(Err(format!("GetModuleHandleW failed: {}", e)));
+                return;
+            }
+        };
+
+        let class_name = w!("ScreenPromptThemeWatcher");
+        let wc = WNDCLASSW {
+            lpfnWndProc: Some(theme_wnd_proc),
+            hInstance: hinstance.into(),
+            lpszClassName: class_name,
+            ..Default::default()
+        };
+        RegisterClassW(&wc);
+
+        // A hidden top-level window (not message-only) so it still receives the
+        // WM_SETTINGCHANGE broadcast.
+        let hwnd = CreateWindowExW(
+            WINDOW_EX_STYLE(0),
+            class_name,
+            w!("ScreenPrompt Theme Watcher"),
+            WS_OVERLAPPED,
+            CW_USEDEFAULT,
+            CW_USEDEFAULT,
+            0,
+            0,
+            None,
+            None,
+            hinstance.into(),
+            None,
+        );
+
+        let hwnd = match hwnd {
+            Ok(h) => h,
+            Err(e) => {
+                let _ = tx.send(Err(format!("CreateWindowExW failed: {}", e)));
+                return;
+            }
+        };
+
+        let tid = GetCurrentThreadId();
+        let _ = tx.send(Ok((hwnd.0 as isize, tid)));
+
+        let mut msg = MSG::default();
+        while GetMessageW(&mut msg, None, 0, 0).as_bool() {}
+
+        let _ = DestroyWindow(hwnd);
+    });
+
+    match rx.recv() {
+        Ok(Ok((hwnd_ptr, thread_id))) => {
+            THEME_HWND.store(hwnd_ptr, Ordering::Relaxed);
+            THEME_THREAD_ID.store(thread_id, Ordering::Relaxed);
+            Ok(())
+        }
+        Ok(Err(e)) => Err(e),
+        Err(e) => Err(format!("Theme watcher communication error: {}", e)),
+    }
+}
+
+/// Tear down the theme watcher thread and its hidden window.
+pub fn uninstall_theme_watcher() -> Result<(), String> {
+    let _guard = get_install_lock()
+        .lock()
+        .map_err(|e| format!("Lock error: {}", e))?;
+
+    let thread_id = THEME_THREAD_ID.swap(0, Ordering::Relaxed);
+    THEME_HWND.store(0, Ordering::Relaxed);
+
+    if let Ok(mut guard) = get_app_handle_store().lock() {
+        *guard = None;
+    }
+
+    if thread_id != 0 {
+        unsafe {
+            let _ = PostThreadMessageW(thread_id, WM_QUIT, WPARAM(0), LPARAM(0));
+        }
+    }
+
+    Ok(())
+}
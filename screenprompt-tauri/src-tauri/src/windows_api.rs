@@ -2,37 +2,115 @@
 // Windows API integration for ScreenPrompt
 
 use tauri::WebviewWindow;
-use windows::Win32::Foundation::HWND;
+use windows::Win32::Foundation::{BOOL, HWND, LPARAM, RECT, TRUE};
+use windows::Win32::Graphics::Gdi::{
+    EnumDisplayMonitors, GetMonitorInfoW, HDC, HMONITOR, MONITORINFOEXW, MONITORINFOF_PRIMARY,
+};
+use windows::Win32::UI::HiDpi::{GetDpiForMonitor, MDT_EFFECTIVE_DPI};
 use windows::Win32::UI::WindowsAndMessaging::{
-    GetWindowLongPtrW, SetWindowLongPtrW, SetLayeredWindowAttributes,
-    GWL_EXSTYLE, LWA_ALPHA, WS_EX_LAYERED, WS_EX_TRANSPARENT,
+    GetWindowLongPtrW, SetLayeredWindowAttributes, SetWindowLongPtrW, SetWindowPos,
+    GWL_EXSTYLE, LWA_ALPHA, SWP_NOACTIVATE, SWP_NOZORDER, WS_EX_LAYERED, WS_EX_TRANSPARENT,
 };
 
 // Windows 10 Display Affinity constants
+const WDA_NONE: u32 = 0x00000000;
 const WDA_EXCLUDEFROMCAPTURE: u32 = 0x00000011;
 
+// Minimum build that honors WDA_EXCLUDEFROMCAPTURE (Windows 10 2004 / 19041).
+const MIN_EXCLUDE_BUILD: u32 = 19041;
+
 // External Windows API functions
 #[link(name = "user32")]
 extern "system" {
     fn SetWindowDisplayAffinity(hwnd: HWND, affinity: u32) -> i32;
+    fn GetWindowDisplayAffinity(hwnd: HWND, affinity: *mut u32) -> i32;
+}
+
+#[link(name = "ntdll")]
+extern "system" {
+    fn RtlGetVersion(info: *mut OSVERSIONINFOW) -> i32;
+}
+
+use windows::Win32::System::SystemInformation::OSVERSIONINFOW;
+
+/// Structured capability report so the frontend can warn when capture
+/// exclusion cannot be honored on the running OS build.
+#[derive(serde::Serialize)]
+pub struct CaptureCapability {
+    /// Windows build number (e.g. 19045, 22631).
+    pub build: u32,
+    /// Whether this build supports `WDA_EXCLUDEFROMCAPTURE`.
+    pub supported: bool,
+    /// Whether exclusion is currently active on the window.
+    pub active: bool,
+    /// Human-readable summary for surfacing in the UI.
+    pub message: String,
+}
+
+/// Query the real OS build via `RtlGetVersion`, which — unlike `GetVersionEx` —
+/// is not subject to application compatibility shimming.
+fn windows_build() -> u32 {
+    let mut info = OSVERSIONINFOW {
+        dwOSVersionInfoSize: std::mem::size_of::<OSVERSIONINFOW>() as u32,
+        ..Default::default()
+    };
+    unsafe {
+        if RtlGetVersion(&mut info) == 0 {
+            info.dwBuildNumber
+        } else {
+            0
+        }
+    }
+}
+
+/// Opt the process into per-monitor-DPI-v2 awareness.
+///
+/// This must run before any window is created so that `GetWindowRect` and the
+/// low-level hook cursor positions share a single physical-pixel coordinate
+/// basis; otherwise forwarded scroll events land in the wrong place on
+/// mixed-DPI multi-monitor setups.
+pub fn set_dpi_awareness() -> Result<(), String> {
+    use windows::Win32::UI::HiDpi::{
+        SetProcessDpiAwarenessContext, DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2,
+    };
+    unsafe {
+        SetProcessDpiAwarenessContext(DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2)
+            .map_err(|e| format!("SetProcessDpiAwarenessContext failed: {}", e))
+    }
 }
 
 /// Check if Windows version supports WDA_EXCLUDEFROMCAPTURE (Build 2004+)
 pub fn check_windows_version() -> Result<String, String> {
-    // For Windows 10/11, we need Build 19041 (2004) or higher
-    // In practice, we just try to use the API and catch errors
-    Ok("Windows 10/11 (version check OK)".to_string())
+    let build = windows_build();
+    if build == 0 {
+        return Err("Unable to determine Windows version".to_string());
+    }
+    if build < MIN_EXCLUDE_BUILD {
+        return Err(format!(
+            "ScreenPrompt requires Windows 10 Build 2004+ or Windows 11 (found build {})",
+            build
+        ));
+    }
+    Ok(format!("Windows build {} (capture exclusion supported)", build))
+}
+
+/// Apply WDA_EXCLUDEFROMCAPTURE to hide window from screen capture.
+///
+/// Thin wrapper over [`set_capture_exclusion`] kept for the setup path and the
+/// existing command.
+pub fn apply_capture_exclusion(window: WebviewWindow) -> Result<(), String> {
+    set_capture_exclusion(window, true)
 }
 
-/// Apply WDA_EXCLUDEFROMCAPTURE to hide window from screen capture
+/// Toggle whether the overlay is excluded from screen capture at runtime.
 ///
 /// This is the 3-step process documented in CLAUDE.md:
 /// 1. Add WS_EX_LAYERED extended style
 /// 2. Call SetLayeredWindowAttributes (makes it compatible with affinity)
-/// 3. Call SetWindowDisplayAffinity
+/// 3. Call SetWindowDisplayAffinity with WDA_EXCLUDEFROMCAPTURE (or WDA_NONE)
 ///
 /// Reference: https://learn.microsoft.com/en-us/answers/questions/700122/setwindowdisplayaffinity-on-windows-11
-pub fn apply_capture_exclusion(window: WebviewWindow) -> Result<(), String> {
+pub fn set_capture_exclusion(window: WebviewWindow, enabled: bool) -> Result<(), String> {
     unsafe {
         // Get the window handle
         let hwnd_val = window.hwnd().map_err(|e| format!("Failed to get HWND: {}", e))?.0;
@@ -50,8 +128,9 @@ pub fn apply_capture_exclusion(window: WebviewWindow) -> Result<(), String> {
             return Err("SetLayeredWindowAttributes failed".to_string());
         }
 
-        // Step 3: Now apply capture exclusion
-        let affinity_result = SetWindowDisplayAffinity(hwnd, WDA_EXCLUDEFROMCAPTURE);
+        // Step 3: Switch the display affinity on or off
+        let affinity = if enabled { WDA_EXCLUDEFROMCAPTURE } else { WDA_NONE };
+        let affinity_result = SetWindowDisplayAffinity(hwnd, affinity);
         if affinity_result == 0 {
             return Err("SetWindowDisplayAffinity failed - requires Windows 10 Build 2004+".to_string());
         }
@@ -60,6 +139,165 @@ pub fn apply_capture_exclusion(window: WebviewWindow) -> Result<(), String> {
     }
 }
 
+/// Read back the window's display affinity and report whether exclusion is
+/// actually active. `WDA_EXCLUDEFROMCAPTURE` silently no-ops on older builds,
+/// so a successful set call is not proof that it took effect.
+pub fn verify_capture_exclusion(window: WebviewWindow) -> Result<bool, String> {
+    unsafe {
+        let hwnd_val = window.hwnd().map_err(|e| format!("Failed to get HWND: {}", e))?.0;
+        let hwnd = HWND(hwnd_val as *mut core::ffi::c_void);
+
+        let mut affinity = WDA_NONE;
+        if GetWindowDisplayAffinity(hwnd, &mut affinity) == 0 {
+            return Err("GetWindowDisplayAffinity failed".to_string());
+        }
+        Ok(affinity == WDA_EXCLUDEFROMCAPTURE)
+    }
+}
+
+/// Combine the OS build check with a live read-back into a single report the
+/// frontend can use to warn when exclusion cannot be honored.
+pub fn capture_capability(window: WebviewWindow) -> Result<CaptureCapability, String> {
+    let build = windows_build();
+    let supported = build >= MIN_EXCLUDE_BUILD;
+    let active = verify_capture_exclusion(window).unwrap_or(false);
+
+    let message = if !supported {
+        format!(
+            "Capture exclusion is unavailable on Windows build {}; this feature needs build {} or newer.",
+            build, MIN_EXCLUDE_BUILD
+        )
+    } else if active {
+        "Capture exclusion is active; the overlay is hidden from screen capture.".to_string()
+    } else {
+        "Capture exclusion is supported but not currently active.".to_string()
+    };
+
+    Ok(CaptureCapability {
+        build,
+        supported,
+        active,
+        message,
+    })
+}
+
+/// A rectangle reported back to the frontend (physical pixels).
+#[derive(serde::Serialize)]
+pub struct MonitorRect {
+    pub left: i32,
+    pub top: i32,
+    pub right: i32,
+    pub bottom: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+impl From<RECT> for MonitorRect {
+    fn from(r: RECT) -> Self {
+        MonitorRect {
+            left: r.left,
+            top: r.top,
+            right: r.right,
+            bottom: r.bottom,
+            width: r.right - r.left,
+            height: r.bottom - r.top,
+        }
+    }
+}
+
+/// Description of a single display, mirroring winit's monitor handle.
+#[derive(serde::Serialize)]
+pub struct MonitorDescriptor {
+    /// Index into the enumeration order, stable within a single call.
+    pub index: usize,
+    /// GDI device name (e.g. `\\.\DISPLAY1`).
+    pub name: String,
+    /// Full monitor bounds in the virtual-desktop coordinate space.
+    pub bounds: MonitorRect,
+    /// Work area (bounds minus taskbar and docked app bars).
+    pub work_area: MonitorRect,
+    /// Whether this is the primary display.
+    pub is_primary: bool,
+    /// Effective scale factor (DPI / 96).
+    pub scale_factor: f64,
+}
+
+// Collector passed through EnumDisplayMonitors' lparam.
+unsafe extern "system" fn enum_monitors_proc(
+    hmonitor: HMONITOR,
+    _hdc: HDC,
+    _rect: *mut RECT,
+    lparam: LPARAM,
+) -> BOOL {
+    let monitors = &mut *(lparam.0 as *mut Vec<MonitorDescriptor>);
+
+    let mut info = MONITORINFOEXW::default();
+    info.monitorInfo.cbSize = std::mem::size_of::<MONITORINFOEXW>() as u32;
+    if GetMonitorInfoW(hmonitor, &mut info.monitorInfo as *mut _).as_bool() {
+        let mut dpi_x = 96u32;
+        let mut dpi_y = 96u32;
+        let _ = GetDpiForMonitor(hmonitor, MDT_EFFECTIVE_DPI, &mut dpi_x, &mut dpi_y);
+
+        let name = String::from_utf16_lossy(&info.szDevice)
+            .trim_end_matches('\0')
+            .to_string();
+
+        let index = monitors.len();
+        monitors.push(MonitorDescriptor {
+            index,
+            name,
+            bounds: info.monitorInfo.rcMonitor.into(),
+            work_area: info.monitorInfo.rcWork.into(),
+            is_primary: info.monitorInfo.dwFlags & MONITORINFOF_PRIMARY != 0,
+            scale_factor: dpi_x as f64 / 96.0,
+        });
+    }
+
+    TRUE
+}
+
+/// Enumerate every connected display via `EnumDisplayMonitors`.
+pub fn enumerate_monitors() -> Result<Vec<MonitorDescriptor>, String> {
+    let mut monitors: Vec<MonitorDescriptor> = Vec::new();
+    unsafe {
+        EnumDisplayMonitors(
+            HDC::default(),
+            None,
+            Some(enum_monitors_proc),
+            LPARAM(&mut monitors as *mut _ as isize),
+        )
+        .ok()
+        .map_err(|e| format!("EnumDisplayMonitors failed: {}", e))?;
+    }
+    Ok(monitors)
+}
+
+/// Move and resize the overlay onto the work area of the monitor at `index`.
+pub fn move_window_to_monitor(window: WebviewWindow, index: usize) -> Result<(), String> {
+    let monitors = enumerate_monitors()?;
+    let target = monitors
+        .get(index)
+        .ok_or_else(|| format!("No monitor at index {}", index))?;
+    let work = &target.work_area;
+
+    unsafe {
+        let hwnd_val = window.hwnd().map_err(|e| format!("Failed to get HWND: {}", e))?.0;
+        let hwnd = HWND(hwnd_val as *mut core::ffi::c_void);
+        SetWindowPos(
+            hwnd,
+            None,
+            work.left,
+            work.top,
+            work.width,
+            work.height,
+            SWP_NOZORDER | SWP_NOACTIVATE,
+        )
+        .map_err(|e| format!("SetWindowPos failed: {}", e))?;
+    }
+
+    Ok(())
+}
+
 /// Toggle click-through (mouse pass-through) mode
 ///
 /// When enabled, clicks pass through the window to apps beneath.